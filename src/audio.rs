@@ -0,0 +1,80 @@
+// Sound effects and looping music, loaded once at startup.
+use macroquad::audio::{self, PlaySoundParams, Sound};
+
+/// Score delta between consecutive "milestone" blips.
+const MILESTONE_STEP: f32 = 100.0;
+
+/// All sound handles used by gameplay. Any field can be `None` if its asset
+/// failed to load, in which case the corresponding `play_*` call is a no-op
+/// so the game still runs fine without audio.
+pub struct Audio {
+    jump: Option<Sound>,
+    crash: Option<Sound>,
+    milestone: Option<Sound>,
+    music: Option<Sound>,
+}
+
+impl Audio {
+    /// Load every clip, tolerating missing files individually.
+    pub async fn load() -> Self {
+        Self {
+            jump: Self::try_load("assets/jump.wav").await,
+            crash: Self::try_load("assets/crash.wav").await,
+            milestone: Self::try_load("assets/milestone.wav").await,
+            music: Self::try_load("assets/music.ogg").await,
+        }
+    }
+
+    async fn try_load(path: &str) -> Option<Sound> {
+        audio::load_sound(path).await.ok()
+    }
+
+    /// Play the jump sound, if loaded.
+    pub fn play_jump(&self) {
+        if let Some(s) = self.jump {
+            audio::play_sound_once(s);
+        }
+    }
+
+    /// Play the crash sound, if loaded.
+    pub fn play_crash(&self) {
+        if let Some(s) = self.crash {
+            audio::play_sound_once(s);
+        }
+    }
+
+    /// Play the milestone blip, if loaded.
+    pub fn play_milestone(&self) {
+        if let Some(s) = self.milestone {
+            audio::play_sound_once(s);
+        }
+    }
+
+    /// Start the looping background track (idempotent-ish: calling this
+    /// again just restarts it, which is fine since it's only called on
+    /// entering `State::Playing`).
+    pub fn start_music(&self) {
+        if let Some(s) = self.music {
+            audio::play_sound(
+                s,
+                PlaySoundParams {
+                    looped: true,
+                    volume: 0.5,
+                },
+            );
+        }
+    }
+
+    /// Stop the background track.
+    pub fn stop_music(&self) {
+        if let Some(s) = self.music {
+            audio::stop_sound(s);
+        }
+    }
+
+    /// Whether `score` just crossed a new `MILESTONE_STEP` threshold since
+    /// the previous frame's score `prev_score`.
+    pub fn crossed_milestone(prev_score: f32, score: f32) -> bool {
+        (prev_score / MILESTONE_STEP) as i32 != (score / MILESTONE_STEP) as i32
+    }
+}
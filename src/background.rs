@@ -0,0 +1,54 @@
+// Scrolling multi-layer parallax backdrop, driven by the current game speed.
+use macroquad::prelude::*;
+
+use crate::resources::Resources;
+
+/// One looping layer: a texture panning left at a fraction of world speed.
+struct Layer {
+    offset: f32,
+    /// Fraction of the game's `speed` this layer scrolls at.
+    parallax: f32,
+}
+
+impl Layer {
+    fn update(&mut self, dt: f32, speed: f32, width: f32) {
+        self.offset = (self.offset + speed * self.parallax * dt) % width;
+    }
+
+    /// Draw two copies side by side so the layer loops seamlessly as it scrolls.
+    fn draw(&self, texture: Texture2D, width: f32, height: f32) {
+        let params = DrawTextureParams {
+            dest_size: Some(vec2(width, height)),
+            ..Default::default()
+        };
+        draw_texture_ex(texture, -self.offset, 0.0, WHITE, params.clone());
+        draw_texture_ex(texture, width - self.offset, 0.0, WHITE, params);
+    }
+}
+
+/// Two-layer parallax background that accelerates with the game's speed.
+pub struct Background {
+    far: Layer,
+    near: Layer,
+}
+
+impl Background {
+    pub fn new() -> Self {
+        Self {
+            far: Layer { offset: 0.0, parallax: 0.2 },
+            near: Layer { offset: 0.0, parallax: 0.5 },
+        }
+    }
+
+    /// Advance scroll offsets from the current game `speed`.
+    pub fn update(&mut self, dt: f32, speed: f32, sw: f32) {
+        self.far.update(dt, speed, sw);
+        self.near.update(dt, speed, sw);
+    }
+
+    /// Draw both layers, called before obstacles/player so they sit behind them.
+    pub fn draw(&self, sw: f32, sh: f32, ground_y: f32, resources: &Resources) {
+        self.far.draw(resources.bg_far, sw, sh);
+        self.near.draw(resources.bg_near, sw, ground_y);
+    }
+}
@@ -3,6 +3,18 @@
 // clarifying inline comments without altering gameplay logic.
 use macroquad::prelude::*;
 
+mod audio;
+mod background;
+mod particles;
+mod resources;
+mod save;
+
+use audio::Audio;
+use background::Background;
+use particles::Particle;
+use resources::Resources;
+use save::SaveData;
+
 /// Downward acceleration applied every frame (pixels / s^2).
 const GRAVITY: f32 = 1800.0;
 /// Initial vertical velocity when jumping (negative => upward).
@@ -23,6 +35,16 @@ const MAX_SPEED: f32 = 140.0;       // difficulty ceiling
 const SPAWN_MIN: f32 = 0.9;
 /// Maximum seconds between spawns (randomized).
 const SPAWN_MAX: f32 = 1.8;
+/// Minimum obstacle speed before overhead-gap obstacles can spawn.
+const OVERHEAD_MIN_SPEED: f32 = 70.0;
+/// Minimum obstacle speed before word obstacles can spawn.
+const WORD_MIN_SPEED: f32 = 50.0;
+/// Font size used for a word obstacle's label at the easiest difficulty.
+const WORD_FONT_SIZE_MIN: u16 = 24;
+/// Font size used for a word obstacle's label at the hardest difficulty.
+const WORD_FONT_SIZE_MAX: u16 = 44;
+/// Word list for word obstacles; swap this list to re-skin the game.
+const WORDS: &[&str] = &["BUG", "ERRO", "CRASH", "PANICO", "EXCECAO", "DEADLINE"];
 
 /// High-level game state machine.
 #[derive(Clone, Copy, PartialEq)]
@@ -63,10 +85,11 @@ impl Player {
         } // “hitbox” levemente menor
     }
     /// Integrate motion & handle jump / ground collision.
-    fn update(&mut self, dt: f32, ground_y: f32, jump_pressed: bool) {
+    fn update(&mut self, dt: f32, ground_y: f32, jump_pressed: bool, audio: &Audio) {
         if jump_pressed && self.on_ground {
             self.vel.y = JUMP_VEL;
             self.on_ground = false;
+            audio.play_jump();
         }
         self.vel.y += GRAVITY * dt;
         self.pos.y += self.vel.y * dt;
@@ -78,34 +101,89 @@ impl Player {
             self.on_ground = true;
         }
     }
-    /// Draw the player using primitive shapes (acts as placeholder art).
-    fn draw(&self) {
-        // Body
-        draw_rectangle(self.pos.x, self.pos.y, PLAYER_W, PLAYER_H, RED);
-        // Simple "antennae" detail.
-        draw_circle(self.pos.x + 12.0, self.pos.y + 12.0, 6.0, BLACK);
-        draw_circle(self.pos.x + 48.0, self.pos.y + 12.0, 6.0, BLACK);
+    /// Draw the ladybug sprite. The shrunken hitbox from `rect()` is left
+    /// untouched; only the visual footprint changes.
+    fn draw(&self, resources: &Resources) {
+        draw_texture_ex(
+            resources.ladybug,
+            self.pos.x,
+            self.pos.y,
+            WHITE,
+            DrawTextureParams {
+                dest_size: Some(vec2(PLAYER_W, PLAYER_H)),
+                ..Default::default()
+            },
+        );
     }
 }
 
+/// Distinguishes ground-anchored blocks from overhead gap obstacles.
+enum ObstacleKind {
+    /// A solid block sitting on the ground; clear it by jumping over.
+    Ground,
+    /// A hanging block with a gap (offset `gap_y`, height `gap_h` from the
+    /// obstacle's top) the player must pass through.
+    Overhead { gap_y: f32, gap_h: f32 },
+}
+
 struct Obstacle {
     pos: Vec2,
     size: Vec2,
     speed: f32,
+    kind: ObstacleKind,
+    /// Word (and the font size it was laid out at) drawn inside a `Ground`
+    /// obstacle instead of plain texture fill, for a cheap content-driven
+    /// variety (see `WORDS`). The font size must be reused verbatim in
+    /// `draw` - it's what the box in `new` was actually sized around, and
+    /// `measure_text`'s reported height is not the same number.
+    label: Option<(String, u16)>,
 }
 impl Obstacle {
-    /// Create an obstacle with randomized width/height anchored on ground.
+    /// Create an obstacle anchored on the ground, randomizing its kind once
+    /// `speed` clears `OVERHEAD_MIN_SPEED` so early play stays simple.
     fn new(x: f32, ground_y: f32, speed: f32) -> Self {
-        // Random simple dimensions.
-        let w = rand::gen_range(40.0, 70.0);
-        let h = rand::gen_range(50.0, 120.0);
-        Self {
-            pos: vec2(x, ground_y - h),
-            size: vec2(w, h),
-            speed,
+        if speed >= OVERHEAD_MIN_SPEED && rand::gen_range(0, 2) == 0 {
+            let h = rand::gen_range(140.0, 200.0);
+            let w = rand::gen_range(40.0, 70.0);
+            let gap_h = rand::gen_range(70.0, 95.0);
+            let gap_y = rand::gen_range(0.0, h - gap_h);
+            Self {
+                pos: vec2(x, ground_y - h),
+                size: vec2(w, h),
+                speed,
+                kind: ObstacleKind::Overhead { gap_y, gap_h },
+                label: None,
+            }
+        } else if speed >= WORD_MIN_SPEED && rand::gen_range(0, 3) == 0 {
+            // Harder (faster) runs get longer, taller words.
+            let difficulty = ((speed - WORD_MIN_SPEED) / (MAX_SPEED - WORD_MIN_SPEED)).clamp(0.0, 1.0);
+            let word = WORDS[rand::gen_range(0, WORDS.len())];
+            let font_size = WORD_FONT_SIZE_MIN
+                + ((WORD_FONT_SIZE_MAX - WORD_FONT_SIZE_MIN) as f32 * difficulty) as u16;
+            let dims = measure_text(word, None, font_size, 1.0);
+            let w = dims.width + 32.0;
+            let h = dims.height + 48.0;
+            Self {
+                pos: vec2(x, ground_y - h),
+                size: vec2(w, h),
+                speed,
+                kind: ObstacleKind::Ground,
+                label: Some((word.to_string(), font_size)),
+            }
+        } else {
+            let w = rand::gen_range(40.0, 70.0);
+            let h = rand::gen_range(50.0, 120.0);
+            Self {
+                pos: vec2(x, ground_y - h),
+                size: vec2(w, h),
+                speed,
+                kind: ObstacleKind::Ground,
+                label: None,
+            }
         }
     }
-    /// Collision rectangle (slightly inset for fairness / readability).
+    /// Bounding rectangle; used for offscreen checks (and, for `Ground`
+    /// obstacles, collision).
     fn rect(&self) -> Rect {
         Rect {
             x: self.pos.x + 4.0,
@@ -114,21 +192,83 @@ impl Obstacle {
             h: self.size.y - 8.0,
         }
     }
+    /// Whether `player_rect` overlaps this obstacle's solid region(s). For
+    /// `Overhead` obstacles the gap itself is not solid.
+    fn collides(&self, player_rect: Rect) -> bool {
+        match self.kind {
+            ObstacleKind::Ground => self.rect().overlaps(&player_rect),
+            ObstacleKind::Overhead { gap_y, gap_h } => {
+                let top = Rect {
+                    x: self.pos.x + 4.0,
+                    y: self.pos.y + 4.0,
+                    w: self.size.x - 8.0,
+                    h: (gap_y - 4.0).max(0.0),
+                };
+                let bottom_y = self.pos.y + gap_y + gap_h;
+                let bottom = Rect {
+                    x: self.pos.x + 4.0,
+                    y: bottom_y,
+                    w: self.size.x - 8.0,
+                    h: (self.pos.y + self.size.y - 4.0 - bottom_y).max(0.0),
+                };
+                top.overlaps(&player_rect) || bottom.overlaps(&player_rect)
+            }
+        }
+    }
     /// Move left according to current speed.
     fn update(&mut self, dt: f32) {
         self.pos.x -= self.speed * dt;
     }
-    /// Render using a rectangle plus small decorative dots.
-    fn draw(&self) {
-        draw_rectangle(self.pos.x, self.pos.y, self.size.x, self.size.y, DARKGREEN);
-        // Dots to give some texture / style.
-        for i in 0..3 {
-            draw_circle(
-                self.pos.x + 10.0 + 12.0 * i as f32,
-                self.pos.y + 10.0,
-                3.0,
-                BLACK,
-            );
+    /// Render the obstacle sprite. The collision rectangles from `rect()`/
+    /// `collides()` are left untouched; only the visual footprint changes.
+    fn draw(&self, resources: &Resources) {
+        match self.kind {
+            ObstacleKind::Ground => {
+                draw_texture_ex(
+                    resources.obstacle,
+                    self.pos.x,
+                    self.pos.y,
+                    WHITE,
+                    DrawTextureParams {
+                        dest_size: Some(self.size),
+                        ..Default::default()
+                    },
+                );
+                if let Some((word, font_size)) = &self.label {
+                    let font_size = *font_size;
+                    let dims = measure_text(word, None, font_size, 1.0);
+                    let text_x = self.pos.x + (self.size.x - dims.width) * 0.5;
+                    let text_y = self.pos.y + (self.size.y + dims.height) * 0.5;
+                    // Faux-bold: draw the word twice with a 1px offset.
+                    draw_text(word, text_x + 1.0, text_y, font_size as f32, BLACK);
+                    draw_text(word, text_x, text_y, font_size as f32, WHITE);
+                }
+            }
+            ObstacleKind::Overhead { gap_y, gap_h } => {
+                // Top hanging block.
+                draw_texture_ex(
+                    resources.obstacle,
+                    self.pos.x,
+                    self.pos.y,
+                    WHITE,
+                    DrawTextureParams {
+                        dest_size: Some(vec2(self.size.x, gap_y)),
+                        ..Default::default()
+                    },
+                );
+                // Bottom block, resuming below the gap.
+                let bottom_y = self.pos.y + gap_y + gap_h;
+                draw_texture_ex(
+                    resources.obstacle,
+                    self.pos.x,
+                    bottom_y,
+                    WHITE,
+                    DrawTextureParams {
+                        dest_size: Some(vec2(self.size.x, self.pos.y + self.size.y - bottom_y)),
+                        ..Default::default()
+                    },
+                );
+            }
         }
     }
     /// Whether the obstacle has completely left the screen on the left side.
@@ -142,13 +282,38 @@ async fn main() {
     // Runtime state variables.
     let mut state = State::Menu;
     let mut score: f32 = 0.0;
-    let mut hi_score: f32 = 0.0;
+    // Loaded once at startup so the title screen's "Recorde" is meaningful
+    // across runs, not just within the current process.
+    let mut save_data = SaveData::load();
     let mut spawn_t: f32 = 0.0;
     let mut next_spawn: f32 = rand::gen_range(SPAWN_MIN, SPAWN_MAX);
     let mut speed = BASE_SPEED;
     let mut obstacles: Vec<Obstacle> = Vec::new();
     // Player is allocated only when starting the game to ensure fresh state.
     let mut player: Option<Player> = None;
+    // Collision/death burst; kept alive (and updated) through GameOver.
+    let mut particles: Vec<Particle> = Vec::new();
+    // Loaded once up front; individual clips degrade to silence if missing.
+    let audio = Audio::load().await;
+    // Sprites are required (unlike audio): surface a visible error screen
+    // instead of panicking if one fails to load.
+    let resources = match Resources::load().await {
+        Ok(r) => r,
+        Err(e) => {
+            loop {
+                clear_background(BLACK);
+                draw_text(
+                    &format!("Failed to load assets: {}", e.0),
+                    32.0,
+                    screen_height() * 0.5,
+                    28.0,
+                    RED,
+                );
+                next_frame().await;
+            }
+        }
+    };
+    let mut background = Background::new();
 
     loop {
         let dt = get_frame_time();
@@ -158,15 +323,19 @@ async fn main() {
 
         clear_background(Color::from_rgba(240, 245, 250, 255));
 
-        // Simple layered background for parallax suggestion.
-        draw_rectangle(0.0, ground_y - 120.0, sw, 20.0, LIGHTGRAY);
-        draw_rectangle(0.0, ground_y - 60.0, sw, 15.0, GRAY);
-        draw_rectangle(
+        // Parallax background, scrolling faster as `speed` ramps up, plus
+        // the ground sprite.
+        background.update(dt, speed, sw);
+        background.draw(sw, sh, ground_y, &resources);
+        draw_texture_ex(
+            resources.ground,
             0.0,
             ground_y,
-            sw,
-            GROUND_H,
-            Color::from_rgba(210, 230, 210, 255),
+            WHITE,
+            DrawTextureParams {
+                dest_size: Some(vec2(sw, GROUND_H)),
+                ..Default::default()
+            },
         );
 
         // Unified input (desktop + mobile / touch).
@@ -181,7 +350,7 @@ async fn main() {
                 draw_text("Ladybug Runner", 32.0, 80.0, 48.0, BLACK);
                 draw_text("Toque ou SPACE para jogar", 32.0, 130.0, 28.0, DARKGRAY);
                 draw_text(
-                    &format!("Recorde: {}", hi_score as i32),
+                    &format!("Recorde: {}", save_data.hi_score as i32),
                     32.0,
                     170.0,
                     24.0,
@@ -193,9 +362,11 @@ async fn main() {
                     score = 0.0;
                     speed = BASE_SPEED;
                     obstacles.clear();
+                    particles.clear();
                     spawn_t = 0.0;
                     next_spawn = rand::gen_range(SPAWN_MIN, SPAWN_MAX);
                     player = Some(Player::new(sw, ground_y));
+                    audio.start_music();
                     state = State::Playing;
                 }
             }
@@ -203,7 +374,7 @@ async fn main() {
                 let player_ref = player.as_mut().expect("Player inexistente");
 
                 // Update player physics.
-                player_ref.update(dt, ground_y, jump_pressed);
+                player_ref.update(dt, ground_y, jump_pressed, &audio);
 
                 // Difficulty scaling (capped).
                 speed = (speed + SPEED_GROWTH * dt).min(MAX_SPEED);
@@ -220,18 +391,23 @@ async fn main() {
                 let mut alive = true;
                 for o in obstacles.iter_mut() {
                     o.update(dt);
-                    o.draw();
-                    if o.rect().overlaps(&player_ref.rect()) {
+                    o.draw(&resources);
+                    if o.collides(player_ref.rect()) {
                         alive = false;
                     }
                 }
                 obstacles.retain(|o| !o.offscreen());
 
-                // Score accrues with speed & time.
+                // Score accrues with speed & time; play a blip every time we
+                // cross a 100-point milestone.
+                let prev_score = score;
                 score += speed * dt * 0.1;
+                if Audio::crossed_milestone(prev_score, score) {
+                    audio.play_milestone();
+                }
 
                 // Draw player last for layering.
-                player_ref.draw();
+                player_ref.draw(&resources);
 
                 // Heads-up display.
                 draw_text(
@@ -242,7 +418,7 @@ async fn main() {
                     BLACK,
                 );
                 draw_text(
-                    &format!("Hi: {}", hi_score as i32),
+                    &format!("Hi: {}", save_data.hi_score as i32),
                     24.0,
                     64.0,
                     24.0,
@@ -250,15 +426,39 @@ async fn main() {
                 );
 
                 if !alive {
-                    if score > hi_score { hi_score = score; }
+                    particles::spawn_burst(player_ref.rect().center(), &mut particles);
+                    audio.play_crash();
+                    audio.stop_music();
+                    if score > save_data.hi_score {
+                        save_data.hi_score = score;
+                    }
+                    save_data.total_runs += 1;
+                    if score > save_data.best_distance {
+                        save_data.best_distance = score;
+                    }
+                    save_data.save();
                     state = State::GameOver;
                 }
+
+                // Keep the burst alive through the transition so it reads as
+                // the cause of death rather than an abrupt cut.
+                for p in particles.iter_mut() {
+                    p.update(dt);
+                    p.draw();
+                }
+                particles.retain(|p| !p.is_dead());
             }
             State::GameOver => {
+                for p in particles.iter_mut() {
+                    p.update(dt);
+                    p.draw();
+                }
+                particles.retain(|p| !p.is_dead());
+
                 draw_text("Game Over!", 32.0, 80.0, 48.0, MAROON);
                 draw_text(&format!("Score: {}", score as i32), 32.0, 130.0, 32.0, BLACK);
                 draw_text(
-                    &format!("Recorde: {}", hi_score as i32),
+                    &format!("Recorde: {}", save_data.hi_score as i32),
                     32.0,
                     170.0,
                     28.0,
@@ -271,9 +471,11 @@ async fn main() {
                     score = 0.0;
                     speed = BASE_SPEED;
                     obstacles.clear();
+                    particles.clear();
                     spawn_t = 0.0;
                     next_spawn = rand::gen_range(SPAWN_MIN, SPAWN_MAX);
                     player = Some(Player::new(sw, ground_y));
+                    audio.start_music();
                     state = State::Playing;
                 }
             }
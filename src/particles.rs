@@ -0,0 +1,62 @@
+// Small impact-particle system used for the collision/death effect.
+use macroquad::prelude::*;
+
+/// Downward acceleration applied to particles (pixels / s^2).
+const PARTICLE_GRAVITY: f32 = 900.0;
+/// Drag factor applied to horizontal velocity each frame.
+const PARTICLE_DRAG: f32 = 0.98;
+/// Number of particles spawned per burst.
+const BURST_MIN: usize = 20;
+const BURST_MAX: usize = 40;
+
+/// A single fading, falling fragment of the death-explosion effect.
+pub struct Particle {
+    pos: Vec2,
+    vel: Vec2,
+    life: f32,
+    max_life: f32,
+    color: Color,
+    size: f32,
+}
+
+impl Particle {
+    /// Integrate motion and tick down the remaining lifetime.
+    pub fn update(&mut self, dt: f32) {
+        self.vel.y += PARTICLE_GRAVITY * dt;
+        self.vel.x *= PARTICLE_DRAG;
+        self.pos += self.vel * dt;
+        self.life -= dt;
+    }
+
+    /// Whether the particle has burned through its lifetime.
+    pub fn is_dead(&self) -> bool {
+        self.life <= 0.0
+    }
+
+    /// Draw with alpha fading proportional to remaining life.
+    pub fn draw(&self) {
+        let alpha = (self.life / self.max_life).clamp(0.0, 1.0);
+        let mut color = self.color;
+        color.a = alpha;
+        draw_circle(self.pos.x, self.pos.y, self.size, color);
+    }
+}
+
+/// Spawn a burst of particles at `center`, used on player/obstacle collision.
+pub fn spawn_burst(center: Vec2, out: &mut Vec<Particle>) {
+    let count = rand::gen_range(BURST_MIN, BURST_MAX + 1);
+    for _ in 0..count {
+        let angle = rand::gen_range(0.0, std::f32::consts::TAU);
+        let speed = rand::gen_range(60.0, 260.0);
+        let vel = vec2(angle.cos(), angle.sin()) * speed;
+        let life = rand::gen_range(0.4, 0.9);
+        out.push(Particle {
+            pos: center,
+            vel,
+            life,
+            max_life: life,
+            color: RED,
+            size: rand::gen_range(2.0, 5.0),
+        });
+    }
+}
@@ -0,0 +1,37 @@
+// Texture asset pipeline: load sprites once up front and hand typed texture
+// handles to the draw methods, instead of hand-drawn primitives.
+use macroquad::prelude::*;
+
+/// Surfaced when an asset fails to load, so the caller can show an on-screen
+/// error instead of panicking (important for web/mobile builds).
+pub struct LoadError(pub String);
+
+/// Every texture used by gameplay rendering.
+pub struct Resources {
+    pub ladybug: Texture2D,
+    pub obstacle: Texture2D,
+    pub ground: Texture2D,
+    /// Farthest, slowest-scrolling background layer.
+    pub bg_far: Texture2D,
+    /// Nearer, faster-scrolling background layer.
+    pub bg_near: Texture2D,
+}
+
+impl Resources {
+    /// Load every texture, bailing out on the first failure.
+    pub async fn load() -> Result<Self, LoadError> {
+        Ok(Self {
+            ladybug: Self::load_one("assets/ladybug.png").await?,
+            obstacle: Self::load_one("assets/obstacle.png").await?,
+            ground: Self::load_one("assets/ground.png").await?,
+            bg_far: Self::load_one("assets/bg_far.png").await?,
+            bg_near: Self::load_one("assets/bg_near.png").await?,
+        })
+    }
+
+    async fn load_one(path: &str) -> Result<Texture2D, LoadError> {
+        load_texture(path)
+            .await
+            .map_err(|e| LoadError(format!("failed to load {path}: {e}")))
+    }
+}
@@ -0,0 +1,64 @@
+// Persistence for the high score and a couple of lifetime stats.
+//
+// Desktop builds read/write a small file next to the executable. Web builds
+// go through `quad-storage`, which wraps the browser's actual `localStorage`,
+// so the record survives page reloads there too.
+#[cfg(not(target_arch = "wasm32"))]
+const SAVE_PATH: &str = "ladybug_save.dat";
+
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY: &str = "ladybug_save";
+
+/// High score and a couple of lifetime stats, persisted across sessions.
+#[derive(Clone, Copy, Default)]
+pub struct SaveData {
+    pub hi_score: f32,
+    pub total_runs: u32,
+    pub best_distance: f32,
+}
+
+impl SaveData {
+    /// Load the saved record, or a fresh zeroed one if nothing was saved yet.
+    pub fn load() -> Self {
+        Self::read_raw()
+            .and_then(|raw| Self::parse(&raw))
+            .unwrap_or_default()
+    }
+
+    /// Persist the current record, overwriting whatever was saved before.
+    pub fn save(&self) {
+        let raw = format!("{}|{}|{}", self.hi_score, self.total_runs, self.best_distance);
+        Self::write_raw(raw);
+    }
+
+    fn parse(raw: &str) -> Option<Self> {
+        let mut parts = raw.split('|');
+        Some(Self {
+            hi_score: parts.next()?.parse().ok()?,
+            total_runs: parts.next()?.parse().ok()?,
+            best_distance: parts.next()?.parse().ok()?,
+        })
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn read_raw() -> Option<String> {
+        std::fs::read_to_string(SAVE_PATH).ok()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn write_raw(raw: String) {
+        // Best-effort: a failed write (read-only sandbox, missing perms, ...)
+        // shouldn't take the game down with it.
+        let _ = std::fs::write(SAVE_PATH, raw);
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn read_raw() -> Option<String> {
+        quad_storage::STORAGE.lock().unwrap().get(STORAGE_KEY)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn write_raw(raw: String) {
+        quad_storage::STORAGE.lock().unwrap().set(STORAGE_KEY, &raw);
+    }
+}